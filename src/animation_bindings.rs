@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use bevy::{
+    input::gamepad::{GamepadAxisType, GamepadButtonType},
+    prelude::*,
+};
+
+use crate::Spine;
+
+/// A condition under which an [`AnimationBinding`] becomes active for a frame.
+#[derive(Debug, Clone)]
+pub enum InputCondition {
+    KeyPressed(KeyCode),
+    MouseButtonPressed(MouseButton),
+    GamepadButtonPressed(GamepadButtonType),
+    GamepadAxisAbove {
+        axis: GamepadAxisType,
+        threshold: f32,
+    },
+}
+
+/// What an [`AnimationBinding`] does to its track while its condition is active.
+#[derive(Debug, Clone)]
+pub enum AnimationAction {
+    /// Sets the track's current animation, replacing whatever is playing.
+    SetAnimation { name: String },
+    /// Queues the animation onto the track after the current one finishes.
+    AddAnimation { name: String, delay: f32 },
+    /// Empties the track.
+    ClearTrack,
+}
+
+/// Maps an [`InputCondition`] to an [`AnimationAction`] applied to a track index.
+///
+/// When multiple bindings targeting the same track are active at once, the one with the
+/// highest `priority` wins, e.g. a held "jump" binding overriding a held "run" binding.
+#[derive(Debug, Clone)]
+pub struct AnimationBinding {
+    pub condition: InputCondition,
+    pub track: usize,
+    pub action: AnimationAction,
+    pub loop_animation: bool,
+    pub mix_duration: f32,
+    pub priority: i32,
+}
+
+/// Declarative input-to-animation bindings for a [`Spine`] entity.
+///
+/// A system in [`SpineSet::OnUpdate`](`crate::SpineSet::OnUpdate`) evaluates these against
+/// Bevy's input resources each frame and applies the highest-priority active binding per track,
+/// only issuing a transition when the winning binding changes.
+#[derive(Component, Default, Debug, Clone)]
+pub struct AnimationBindings {
+    pub bindings: Vec<AnimationBinding>,
+    applied: HashMap<usize, usize>,
+}
+
+fn condition_active(
+    condition: &InputCondition,
+    keys: &Input<KeyCode>,
+    mouse: &Input<MouseButton>,
+    gamepad_buttons: &Input<GamepadButton>,
+    gamepad_axes: &Axis<GamepadAxis>,
+    gamepads: &Gamepads,
+) -> bool {
+    match condition {
+        InputCondition::KeyPressed(key) => keys.pressed(*key),
+        InputCondition::MouseButtonPressed(button) => mouse.pressed(*button),
+        InputCondition::GamepadButtonPressed(button_type) => gamepads.iter().any(|gamepad| {
+            gamepad_buttons.pressed(GamepadButton::new(gamepad, *button_type))
+        }),
+        InputCondition::GamepadAxisAbove { axis, threshold } => gamepads.iter().any(|gamepad| {
+            gamepad_axes
+                .get(GamepadAxis::new(gamepad, *axis))
+                .map(|value| value >= *threshold)
+                .unwrap_or(false)
+        }),
+    }
+}
+
+pub(crate) fn spine_update_animation_bindings(
+    keys: Res<Input<KeyCode>>,
+    mouse: Res<Input<MouseButton>>,
+    gamepad_buttons: Res<Input<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    gamepads: Res<Gamepads>,
+    mut query: Query<(&mut AnimationBindings, &mut Spine)>,
+) {
+    for (mut animation_bindings, mut spine) in query.iter_mut() {
+        let mut winners: HashMap<usize, usize> = HashMap::new();
+        for (index, binding) in animation_bindings.bindings.iter().enumerate() {
+            if !condition_active(
+                &binding.condition,
+                &keys,
+                &mouse,
+                &gamepad_buttons,
+                &gamepad_axes,
+                &gamepads,
+            ) {
+                continue;
+            }
+            let is_higher_priority = winners
+                .get(&binding.track)
+                .map(|&winner| animation_bindings.bindings[winner].priority < binding.priority)
+                .unwrap_or(true);
+            if is_higher_priority {
+                winners.insert(binding.track, index);
+            }
+        }
+
+        animation_bindings.applied.retain(|track, _| winners.contains_key(track));
+        for (track, index) in winners {
+            if animation_bindings.applied.get(&track) == Some(&index) {
+                continue;
+            }
+            let binding = animation_bindings.bindings[index].clone();
+            match &binding.action {
+                AnimationAction::SetAnimation { name } => {
+                    if let Ok(mut track_entry) = spine
+                        .animation_state
+                        .set_animation_by_name(binding.track, name, binding.loop_animation)
+                    {
+                        track_entry.set_mix_duration(binding.mix_duration);
+                    }
+                }
+                AnimationAction::AddAnimation { name, delay } => {
+                    if let Ok(mut track_entry) = spine.animation_state.add_animation_by_name(
+                        binding.track,
+                        name,
+                        binding.loop_animation,
+                        *delay,
+                    ) {
+                        track_entry.set_mix_duration(binding.mix_duration);
+                    }
+                }
+                AnimationAction::ClearTrack => {
+                    spine.animation_state.clear_track(binding.track);
+                }
+            }
+            animation_bindings.applied.insert(track, index);
+        }
+    }
+}
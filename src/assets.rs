@@ -7,6 +7,7 @@ use bevy::{
     utils::BoxedFuture,
 };
 use rusty_spine::SpineError;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -15,6 +16,43 @@ pub enum SpineLoaderError {
     Io(#[from] std::io::Error),
     #[error("Spine error: {0}")]
     Spine(#[from] SpineError),
+    /// The atlas file references a page with no name, so its image can't be resolved.
+    #[error("Atlas page has no image name: {0}")]
+    MissingPage(String),
+}
+
+/// Per-asset settings for the Spine loaders ([`AtlasLoader`], [`SkeletonJsonLoader`],
+/// [`SkeletonBinaryLoader`]), consumed through Bevy's `load_with_settings`.
+///
+/// ```
+/// use bevy::prelude::*;
+///
+/// fn load_skeleton(asset_server: Res<AssetServer>) {
+///     // `premultiplied_alpha` and `default_scale` are applied to the resulting
+///     // `SkeletonData` once this skeleton file has loaded.
+///     let _handle = asset_server.load_with_settings(
+///         "spineboy.json",
+///         |settings: &mut bevy_spine::SkeletonSettings| settings.premultiplied_alpha = true,
+///     );
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SkeletonSettings {
+    /// Whether the skeleton's textures use premultiplied alpha, affecting blend mode selection.
+    /// Applied to [`SkeletonData::premultiplied_alpha`] once the skeleton file resolves.
+    pub premultiplied_alpha: bool,
+    /// Default scale for the skeleton, copied onto [`SkeletonData::default_scale`] once the
+    /// skeleton file resolves.
+    pub default_scale: Vec2,
+}
+
+impl Default for SkeletonSettings {
+    fn default() -> Self {
+        Self {
+            premultiplied_alpha: false,
+            default_scale: Vec2::ONE,
+        }
+    }
 }
 
 /// Bevy asset for [`rusty_spine::Atlas`], loaded from `.atlas` files.
@@ -24,6 +62,14 @@ pub enum SpineLoaderError {
 #[uuid = "e58e872a-9d35-41bf-b561-95f843686004"]
 pub struct Atlas {
     pub atlas: Arc<rusty_spine::Atlas>,
+    /// The settings this atlas was loaded with.
+    pub settings: SkeletonSettings,
+    /// Handles to the page images referenced by this atlas, in atlas page order. Holding these
+    /// registers them as load dependencies, so Bevy can hot-reload the textures; a page file
+    /// that's actually missing on disk surfaces as an async asset-load failure through Bevy's
+    /// normal dependency reporting, not as a [`SpineLoaderError`] (that's only raised here for
+    /// an atlas page with no image name at all).
+    pub page_handles: Vec<Handle<Image>>,
 }
 
 #[derive(Default)]
@@ -31,26 +77,37 @@ pub(crate) struct AtlasLoader;
 
 impl AssetLoader for AtlasLoader {
     type Asset = Atlas;
-    type Settings = ();
+    type Settings = SkeletonSettings;
     type Error = SpineLoaderError;
 
     fn load<'a>(
         &'a self,
         reader: &'a mut Reader,
-        _settings: &'a Self::Settings,
+        settings: &'a Self::Settings,
         load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
         Box::pin(async move {
             let mut bytes = Vec::new();
             reader.read_to_end(&mut bytes).await?;
+            let dir = load_context
+                .path()
+                .parent()
+                .unwrap_or_else(|| Path::new(""));
+            let atlas = rusty_spine::Atlas::new(&bytes, dir)?;
+            let mut page_handles = Vec::new();
+            for page in atlas.pages() {
+                let page_name = page.name();
+                if page_name.is_empty() {
+                    return Err(SpineLoaderError::MissingPage(
+                        load_context.path().display().to_string(),
+                    ));
+                }
+                page_handles.push(load_context.load(dir.join(page_name)));
+            }
             Ok(Atlas {
-                atlas: Arc::new(rusty_spine::Atlas::new(
-                    &bytes,
-                    load_context
-                        .path()
-                        .parent()
-                        .unwrap_or_else(|| Path::new("")),
-                )?),
+                atlas: Arc::new(atlas),
+                settings: settings.clone(),
+                page_handles,
             })
         })
     }
@@ -67,6 +124,8 @@ impl AssetLoader for AtlasLoader {
 #[uuid = "8637cf16-90c4-4825-bdf2-277e38788365"]
 pub struct SkeletonJson {
     pub json: Vec<u8>,
+    /// The settings this skeleton was loaded with, applied to [`SkeletonData`] once resolved.
+    pub settings: SkeletonSettings,
 }
 
 #[derive(Default)]
@@ -74,13 +133,13 @@ pub(crate) struct SkeletonJsonLoader;
 
 impl AssetLoader for SkeletonJsonLoader {
     type Asset = SkeletonJson;
-    type Settings = ();
+    type Settings = SkeletonSettings;
     type Error = SpineLoaderError;
 
     fn load<'a>(
         &'a self,
         reader: &'a mut Reader,
-        _settings: &'a Self::Settings,
+        settings: &'a Self::Settings,
         _load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
         Box::pin(async move {
@@ -88,6 +147,7 @@ impl AssetLoader for SkeletonJsonLoader {
             reader.read_to_end(&mut bytes).await?;
             Ok(SkeletonJson {
                 json: bytes.to_vec(),
+                settings: settings.clone(),
             })
         })
     }
@@ -104,6 +164,8 @@ impl AssetLoader for SkeletonJsonLoader {
 #[uuid = "2a2a342a-29ae-4417-adf5-06ea7f0732d0"]
 pub struct SkeletonBinary {
     pub binary: Vec<u8>,
+    /// The settings this skeleton was loaded with, applied to [`SkeletonData`] once resolved.
+    pub settings: SkeletonSettings,
 }
 
 #[derive(Default)]
@@ -111,13 +173,13 @@ pub(crate) struct SkeletonBinaryLoader;
 
 impl AssetLoader for SkeletonBinaryLoader {
     type Asset = SkeletonBinary;
-    type Settings = ();
+    type Settings = SkeletonSettings;
     type Error = SpineLoaderError;
 
     fn load<'a>(
         &'a self,
         reader: &'a mut Reader,
-        _settings: &'a Self::Settings,
+        settings: &'a Self::Settings,
         _load_context: &'a mut LoadContext,
     ) -> BoxedFuture<'a, Result<Self::Asset, Self::Error>> {
         Box::pin(async move {
@@ -125,6 +187,7 @@ impl AssetLoader for SkeletonBinaryLoader {
             reader.read_to_end(&mut bytes).await?;
             Ok(SkeletonBinary {
                 binary: bytes.to_vec(),
+                settings: settings.clone(),
             })
         })
     }
@@ -145,6 +208,10 @@ pub struct SkeletonData {
     pub kind: SkeletonDataKind,
     pub status: SkeletonDataStatus,
     pub premultiplied_alpha: bool,
+    /// Default scale taken from the [`SkeletonSettings`] the skeleton file was loaded with.
+    /// The plugin stores it here only; no system in this crate applies it to a
+    /// [`SkeletonController`](`crate::SkeletonController`).
+    pub default_scale: Vec2,
 }
 
 #[derive(Debug)]
@@ -195,6 +262,7 @@ impl SkeletonData {
             kind: SkeletonDataKind::JsonFile(json),
             status: SkeletonDataStatus::Loading,
             premultiplied_alpha: false,
+            default_scale: Vec2::ONE,
         }
     }
 
@@ -232,6 +300,7 @@ impl SkeletonData {
             kind: SkeletonDataKind::BinaryFile(binary),
             status: SkeletonDataStatus::Loading,
             premultiplied_alpha: false,
+            default_scale: Vec2::ONE,
         }
     }
 
@@ -246,3 +315,40 @@ impl SkeletonData {
         }
     }
 }
+
+/// Copies [`SkeletonSettings`] from a loaded [`SkeletonJson`]/[`SkeletonBinary`] asset onto its
+/// referencing [`SkeletonData`], so settings passed to `load_with_settings` take effect without
+/// touching the `SkeletonController` at spawn time.
+pub(crate) fn spine_apply_skeleton_settings(
+    mut skeleton_data_assets: ResMut<Assets<SkeletonData>>,
+    skeleton_json_assets: Res<Assets<SkeletonJson>>,
+    skeleton_binary_assets: Res<Assets<SkeletonBinary>>,
+) {
+    // `Assets::get_mut` flags the asset as modified unconditionally, so only reach for it once
+    // we already know (via a read-only pass) that the settings actually changed; otherwise this
+    // would fire an `AssetEvent::Modified` for every `SkeletonData` on every frame.
+    let mut changed = Vec::new();
+    for (id, skeleton_data) in skeleton_data_assets.iter() {
+        let settings = match &skeleton_data.kind {
+            SkeletonDataKind::JsonFile(handle) => {
+                skeleton_json_assets.get(handle).map(|json| &json.settings)
+            }
+            SkeletonDataKind::BinaryFile(handle) => skeleton_binary_assets
+                .get(handle)
+                .map(|binary| &binary.settings),
+        };
+        if let Some(settings) = settings {
+            if skeleton_data.premultiplied_alpha != settings.premultiplied_alpha
+                || skeleton_data.default_scale != settings.default_scale
+            {
+                changed.push((id, settings.premultiplied_alpha, settings.default_scale));
+            }
+        }
+    }
+    for (id, premultiplied_alpha, default_scale) in changed {
+        if let Some(skeleton_data) = skeleton_data_assets.get_mut(id) {
+            skeleton_data.premultiplied_alpha = premultiplied_alpha;
+            skeleton_data.default_scale = default_scale;
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use rusty_spine::{BoundingBoxAttachment, MeshAttachment, Slot};
+
+use crate::Spine;
+
+/// World-space attachment geometry for an entity's active slots, refreshed every frame in
+/// [`SpineSet::OnUpdate`](`crate::SpineSet::OnUpdate`) from the skeleton's current pose. The
+/// refresh is not ordered against other systems in that set.
+///
+/// Keyed by slot name, each entry holds the deformed world-space vertices of that slot's active
+/// bounding-box (or mesh) attachment, ready to be fed into a physics backend for per-frame,
+/// animation-accurate collision.
+#[derive(Component, Default, Debug, Clone)]
+pub struct SpineColliders {
+    pub polygons: HashMap<String, Vec<Vec2>>,
+}
+
+pub(crate) fn spine_update_colliders(
+    mut spine_query: Query<(&Spine, &GlobalTransform, &mut SpineColliders)>,
+) {
+    for (spine, global_transform, mut colliders) in spine_query.iter_mut() {
+        colliders.polygons.clear();
+        for slot in spine.skeleton.slots() {
+            let Some(attachment) = slot.attachment() else {
+                continue;
+            };
+            let world_vertices = if let Some(bounding_box) = attachment.as_bounding_box() {
+                Some(compute_world_vertices(&bounding_box, &slot))
+            } else if let Some(mesh) = attachment.as_mesh() {
+                Some(compute_world_vertices(&mesh, &slot))
+            } else {
+                None
+            };
+            let Some(world_vertices) = world_vertices else {
+                continue;
+            };
+            let polygon = world_vertices
+                .chunks_exact(2)
+                .map(|xy| {
+                    global_transform
+                        .transform_point(Vec3::new(xy[0], xy[1], 0.))
+                        .truncate()
+                })
+                .collect();
+            colliders
+                .polygons
+                .insert(slot.data().name().to_owned(), polygon);
+        }
+    }
+}
+
+/// A vertex attachment whose deformed world-space vertices can be computed for a slot, shared
+/// by [`BoundingBoxAttachment`] and [`MeshAttachment`] so callers don't need to duplicate the
+/// length + compute dance per attachment type.
+trait WorldVerticesAttachment {
+    fn world_vertices_length(&self) -> i32;
+
+    /// # Safety
+    /// `slot` must belong to the same skeleton the attachment was resolved from.
+    unsafe fn compute_world_vertices(
+        &self,
+        slot: &Slot,
+        start: i32,
+        count: i32,
+        world_vertices: &mut [f32],
+        offset: usize,
+        stride: usize,
+    );
+}
+
+impl WorldVerticesAttachment for BoundingBoxAttachment {
+    fn world_vertices_length(&self) -> i32 {
+        BoundingBoxAttachment::world_vertices_length(self)
+    }
+
+    unsafe fn compute_world_vertices(
+        &self,
+        slot: &Slot,
+        start: i32,
+        count: i32,
+        world_vertices: &mut [f32],
+        offset: usize,
+        stride: usize,
+    ) {
+        BoundingBoxAttachment::compute_world_vertices(
+            self,
+            slot,
+            start,
+            count,
+            world_vertices,
+            offset,
+            stride,
+        )
+    }
+}
+
+impl WorldVerticesAttachment for MeshAttachment {
+    fn world_vertices_length(&self) -> i32 {
+        MeshAttachment::world_vertices_length(self)
+    }
+
+    unsafe fn compute_world_vertices(
+        &self,
+        slot: &Slot,
+        start: i32,
+        count: i32,
+        world_vertices: &mut [f32],
+        offset: usize,
+        stride: usize,
+    ) {
+        MeshAttachment::compute_world_vertices(
+            self,
+            slot,
+            start,
+            count,
+            world_vertices,
+            offset,
+            stride,
+        )
+    }
+}
+
+fn compute_world_vertices(attachment: &impl WorldVerticesAttachment, slot: &Slot) -> Vec<f32> {
+    let count = attachment.world_vertices_length();
+    let mut buffer = vec![0.; count as usize];
+    // Safety: `slot` belongs to the same skeleton the attachment was resolved from, which is
+    // the contract `compute_world_vertices` requires.
+    unsafe {
+        attachment.compute_world_vertices(slot, 0, count, &mut buffer, 0, 2);
+    }
+    buffer
+}
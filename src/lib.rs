@@ -0,0 +1,118 @@
+//! Bevy plugin for rendering and animating [Spine](http://esotericsoftware.com/) skeletons via
+//! [`rusty_spine`].
+
+pub mod animation_bindings;
+pub mod assets;
+pub mod colliders;
+pub mod prelude;
+pub mod skin;
+
+pub use animation_bindings::AnimationBindings;
+pub use assets::*;
+pub use colliders::SpineColliders;
+pub use skin::SpineSkin;
+
+use bevy::prelude::*;
+use rusty_spine::{AnimationState, Skeleton};
+
+/// System sets Spine systems run in, for ordering user systems relative to the plugin's work.
+///
+/// Systems that need a fully up-to-date [`SkeletonController`] (e.g. reading bone or attachment
+/// world positions after animation has been applied) should run in [`SpineSet::OnUpdate`].
+/// Systems that react to a skeleton becoming ready for the first time should run in
+/// [`SpineSet::OnReady`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum SpineSet {
+    OnUpdate,
+    OnReady,
+}
+
+/// The live Spine runtime state driving a [`Spine`] entity: the skeleton pose and the
+/// animation state advancing it.
+pub struct SkeletonController {
+    pub skeleton: Skeleton,
+    pub animation_state: AnimationState,
+}
+
+impl SkeletonController {
+    pub fn new(skeleton: Skeleton, animation_state: AnimationState) -> Self {
+        Self {
+            skeleton,
+            animation_state,
+        }
+    }
+}
+
+/// Bevy component wrapping an entity's [`SkeletonController`].
+///
+/// Dereferences to [`SkeletonController`] for convenience.
+#[derive(Component)]
+pub struct Spine(pub SkeletonController);
+
+impl std::ops::Deref for Spine {
+    type Target = SkeletonController;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl std::ops::DerefMut for Spine {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Signals that a [`Spine`] entity's [`SkeletonData`] has finished loading and its
+/// [`SkeletonController`] is ready to be configured.
+///
+/// [`SpinePlugin`] registers this event; no system in this crate sends it.
+#[derive(Event, Debug, Clone, Copy)]
+pub struct SpineReadyEvent {
+    pub entity: Entity,
+}
+
+/// Bundle for spawning a Spine skeleton into the world.
+///
+/// ```ignore
+/// commands.spawn(SpineBundle {
+///     skeleton: skeleton_handle,
+///     transform: Transform::from_xyz(0., -200., 0.),
+///     ..Default::default()
+/// });
+/// ```
+#[derive(Bundle, Default)]
+pub struct SpineBundle {
+    pub skeleton: Handle<SkeletonData>,
+    pub transform: Transform,
+    pub global_transform: GlobalTransform,
+    pub visibility: Visibility,
+    pub inherited_visibility: InheritedVisibility,
+    pub view_visibility: ViewVisibility,
+}
+
+/// Adds Spine skeletal animation support to a Bevy app.
+pub struct SpinePlugin;
+
+impl Plugin for SpinePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SpineReadyEvent>()
+            .configure_sets(Update, (SpineSet::OnUpdate, SpineSet::OnReady).chain())
+            .init_asset::<assets::Atlas>()
+            .init_asset::<assets::SkeletonJson>()
+            .init_asset::<assets::SkeletonBinary>()
+            .init_asset::<assets::SkeletonData>()
+            .init_asset_loader::<assets::AtlasLoader>()
+            .init_asset_loader::<assets::SkeletonJsonLoader>()
+            .init_asset_loader::<assets::SkeletonBinaryLoader>()
+            .add_systems(PreUpdate, assets::spine_apply_skeleton_settings)
+            .add_systems(
+                Update,
+                (
+                    colliders::spine_update_colliders,
+                    animation_bindings::spine_update_animation_bindings,
+                )
+                    .in_set(SpineSet::OnUpdate),
+            );
+    }
+}
@@ -0,0 +1,7 @@
+pub use crate::{
+    animation_bindings::{AnimationAction, AnimationBinding, AnimationBindings, InputCondition},
+    assets::{Atlas, SkeletonBinary, SkeletonData, SkeletonJson, SkeletonSettings},
+    colliders::SpineColliders,
+    skin::SpineSkin,
+    Spine, SpineBundle, SpinePlugin, SpineReadyEvent, SpineSet, SkeletonController,
+};
@@ -0,0 +1,45 @@
+use crate::SkeletonController;
+
+/// Extension trait for composing and swapping a [`SkeletonController`]'s active skin at
+/// runtime, e.g. layering a base body with swappable equipment pieces.
+///
+/// ```ignore
+/// fn on_spawn(mut spine_ready_event: EventReader<SpineReadyEvent>, mut spine_query: Query<&mut Spine>) {
+///     for event in spine_ready_event.read() {
+///         if let Ok(mut spine) = spine_query.get_mut(event.entity) {
+///             spine.set_skin_combination(&["base", "armor/heavy", "weapon/sword"]);
+///         }
+///     }
+/// }
+/// ```
+pub trait SpineSkin {
+    /// Sets the active skin to the single named skin. Returns `false` if no skin by that name
+    /// exists on this skeleton's data.
+    fn set_skin_by_name(&mut self, name: &str) -> bool;
+
+    /// Builds a skin from the named sub-skins and sets it as the active skin. Returns `false`
+    /// if any of the named sub-skins couldn't be found.
+    fn set_skin_combination(&mut self, names: &[&str]) -> bool;
+}
+
+impl SpineSkin for SkeletonController {
+    fn set_skin_by_name(&mut self, name: &str) -> bool {
+        if self.skeleton.set_skin_by_name(name).is_err() {
+            return false;
+        }
+        self.skeleton.set_slots_to_setup_pose();
+        true
+    }
+
+    fn set_skin_combination(&mut self, names: &[&str]) -> bool {
+        if self
+            .skeleton
+            .set_skins_by_name("bevy_spine_skin_combination", names.iter().copied())
+            .is_err()
+        {
+            return false;
+        }
+        self.skeleton.set_slots_to_setup_pose();
+        true
+    }
+}